@@ -1874,6 +1874,28 @@ impl Parser {
         }
 
         let peeked = self.peek();
+
+        if let TokenKind::Assign
+        | TokenKind::Replace
+        | TokenKind::AddAssign
+        | TokenKind::SubAssign
+        | TokenKind::DivAssign
+        | TokenKind::MulAssign
+        | TokenKind::PowAssign
+        | TokenKind::ModAssign
+        | TokenKind::ShlAssign
+        | TokenKind::ShrAssign
+        | TokenKind::UnsignedShrAssign
+        | TokenKind::BitOrAssign
+        | TokenKind::BitAndAssign
+        | TokenKind::BitXorAssign = peeked.kind
+        {
+            error!(
+                peeked.location.clone(),
+                "constants can't be assigned new values"
+            );
+        }
+
         let same_line = peeked.same_line_as(&start);
         let name = Constant::from(start);
 
@@ -3595,6 +3617,9 @@ mod tests {
         assert_error!("import foo.", cols(11, 11));
         assert_error!("import foo.(", cols(12, 12));
         assert_error!("import foo.)", cols(12, 12));
+        assert_error!("import Foo", cols(8, 10));
+        assert_error!("import Foo.bar", cols(8, 10));
+        assert_error!("import foo.Bar.baz", cols(15, 15));
     }
 
     #[test]
@@ -6609,6 +6634,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_constant_expression() {
+        assert_error_expr!("Foo = 10", cols(5, 5));
+        assert_error_expr!("Foo := 10", cols(5, 6));
+        assert_error_expr!("Foo += 10", cols(5, 6));
+        assert_error_expr!("Foo >>>= 10", cols(5, 8));
+    }
+
     #[test]
     fn test_identifier_expression() {
         assert_eq!(
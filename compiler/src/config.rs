@@ -2,6 +2,7 @@
 use crate::presenters::{JSONPresenter, Presenter, TextPresenter};
 use crate::target::Target;
 use std::env;
+use std::ffi::OsStr;
 use std::fs::create_dir_all;
 use std::path::{Path, PathBuf};
 use types::module_name::ModuleName;
@@ -24,6 +25,12 @@ pub(crate) const TESTS: &str = "test";
 /// The name of the directory to store build files in.
 const BUILD: &str = "build";
 
+/// The environment variable containing extra source directories to search.
+///
+/// The directories are separated using the platform's path separator (e.g. `:`
+/// on Unix systems), similar to the `PATH` environment variable.
+const PATH_VAR: &str = "INKO_PATH";
+
 fn create_directory(path: &Path) -> Result<(), String> {
     if path.is_dir() {
         return Ok(());
@@ -198,10 +205,24 @@ impl Config {
         }
     }
 
-    fn add_default_source_directories(&mut self) {
+    fn add_default_source_directories(&mut self, paths: Option<&OsStr>) {
         if self.std.is_dir() {
             self.sources.push(self.std.clone());
         }
+
+        // The directories from the environment come after the standard
+        // library, and before any directories added using the -i option.
+        if let Some(paths) = paths {
+            self.add_source_directories_from(paths);
+        }
+    }
+
+    fn add_source_directories_from(&mut self, paths: &OsStr) {
+        for path in env::split_paths(paths) {
+            if !path.as_os_str().is_empty() {
+                self.add_source_directory(path);
+            }
+        }
     }
 
     fn add_default_implicit_imports(&mut self) {
@@ -267,8 +288,52 @@ impl Default for Config {
     fn default() -> Self {
         let mut cfg = Config::new();
 
-        cfg.add_default_source_directories();
+        cfg.add_default_source_directories(env::var_os(PATH_VAR).as_deref());
         cfg.add_default_implicit_imports();
         cfg
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_source_directories_from() {
+        let mut config = Config::new();
+        let paths = env::join_paths(["/foo", "", "/bar"]).unwrap();
+
+        config.add_source_directories_from(&paths);
+
+        assert_eq!(
+            config.sources,
+            vec![PathBuf::from("/foo"), PathBuf::from("/bar")]
+        );
+    }
+
+    #[test]
+    fn test_add_default_source_directories() {
+        let mut config = Config::new();
+        let std = env::temp_dir();
+        let paths = env::join_paths(["/foo", "/bar"]).unwrap();
+
+        config.std = std.clone();
+        config.add_default_source_directories(Some(&paths));
+
+        assert_eq!(
+            config.sources,
+            vec![std, PathBuf::from("/foo"), PathBuf::from("/bar")]
+        );
+    }
+
+    #[test]
+    fn test_add_default_source_directories_without_paths() {
+        let mut config = Config::new();
+        let std = env::temp_dir();
+
+        config.std = std.clone();
+        config.add_default_source_directories(None);
+
+        assert_eq!(config.sources, vec![std]);
+    }
+}
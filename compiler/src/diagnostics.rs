@@ -379,18 +379,20 @@ impl Diagnostics {
         &mut self,
         name: &str,
         receiver: String,
+        suggestion: Option<&str>,
         file: PathBuf,
         location: SourceLocation,
     ) {
-        self.error(
-            DiagnosticId::InvalidSymbol,
-            format!(
-                "the method '{}' isn't defined for type '{}'",
-                name, receiver
-            ),
-            file,
-            location,
+        let mut msg = format!(
+            "the method '{}' isn't defined for type '{}'",
+            name, receiver
         );
+
+        if let Some(similar) = suggestion {
+            msg.push_str(&format!(", did you mean '{}'?", similar));
+        }
+
+        self.error(DiagnosticId::InvalidSymbol, msg, file, location);
     }
 
     pub(crate) fn invalid_builtin_function(
@@ -1140,6 +1140,7 @@ impl<'a> CheckConstant<'a> {
                 self.state.diagnostics.undefined_method(
                     name,
                     format_type(self.db(), typ),
+                    None,
                     self.file(),
                     location.clone(),
                 );
@@ -1174,9 +1175,13 @@ impl<'a> CheckConstant<'a> {
                 );
             }
             MethodLookup::None => {
+                let similar =
+                    rec_id.similar_method(self.db(), name, self.module, false);
+
                 self.state.diagnostics.undefined_method(
                     name,
                     format_type(self.db(), receiver),
+                    similar.as_deref(),
                     self.file(),
                     location.clone(),
                 );
@@ -3307,11 +3312,12 @@ impl<'a> CheckMethodBody<'a> {
                         TypeRef::nil()
                     }
                     _ => {
-                        self.state.diagnostics.undefined_method(
+                        self.undefined_method(
                             &setter,
-                            self.fmt(receiver),
-                            self.file(),
-                            node.location.clone(),
+                            receiver,
+                            rec_id,
+                            allow_type_private,
+                            &node.location,
                         );
 
                         TypeRef::Error
@@ -3488,6 +3494,7 @@ impl<'a> CheckMethodBody<'a> {
             self.state.diagnostics.undefined_method(
                 &node.name.name,
                 self.fmt(receiver),
+                None,
                 self.file(),
                 node.location.clone(),
             );
@@ -3660,11 +3667,12 @@ impl<'a> CheckMethodBody<'a> {
                         ret
                     }
                     _ => {
-                        self.state.diagnostics.undefined_method(
+                        self.undefined_method(
                             &node.name.name,
-                            self.fmt(receiver),
-                            self.file(),
-                            node.location.clone(),
+                            receiver,
+                            rec_id,
+                            allow_type_private,
+                            &node.location,
                         );
 
                         TypeRef::Error
@@ -3672,11 +3680,12 @@ impl<'a> CheckMethodBody<'a> {
                 };
             }
             MethodLookup::None => {
-                self.state.diagnostics.undefined_method(
+                self.undefined_method(
                     &node.name.name,
-                    self.fmt(receiver),
-                    self.file(),
-                    node.location.clone(),
+                    receiver,
+                    rec_id,
+                    allow_type_private,
+                    &node.location,
                 );
 
                 return TypeRef::Error;
@@ -3900,6 +3909,21 @@ impl<'a> CheckMethodBody<'a> {
             return TypeRef::Error;
         };
 
+        if let Some(expected) = id.arity() {
+            let given = node.arguments.len();
+
+            if given != expected {
+                self.state.diagnostics.incorrect_call_arguments(
+                    given,
+                    expected,
+                    self.file(),
+                    node.location.clone(),
+                );
+
+                return TypeRef::Error;
+            }
+        }
+
         let returns = id.return_type();
 
         node.info = Some(BuiltinCallInfo { id, returns });
@@ -4311,6 +4335,30 @@ impl<'a> CheckMethodBody<'a> {
         format_type(self.db(), typ)
     }
 
+    fn undefined_method(
+        &mut self,
+        name: &str,
+        receiver: TypeRef,
+        receiver_id: TypeId,
+        allow_type_private: bool,
+        location: &SourceLocation,
+    ) {
+        let similar = receiver_id.similar_method(
+            self.db(),
+            name,
+            self.module,
+            allow_type_private,
+        );
+
+        self.state.diagnostics.undefined_method(
+            name,
+            self.fmt(receiver),
+            similar.as_deref(),
+            self.file(),
+            location.clone(),
+        );
+    }
+
     fn invalid_static_call(
         &mut self,
         name: &str,
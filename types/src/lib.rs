@@ -261,6 +261,14 @@ impl TypeParameterId {
         None
     }
 
+    fn method_names(self, db: &Database) -> Vec<&String> {
+        self.get(db)
+            .requirements
+            .iter()
+            .flat_map(|req| req.instance_of.method_names(db))
+            .collect()
+    }
+
     pub fn set_original(self, db: &mut Database, parameter: TypeParameterId) {
         self.get_mut(db).original = Some(parameter);
     }
@@ -556,6 +564,21 @@ impl TraitId {
         None
     }
 
+    fn method_names(self, db: &Database) -> Vec<&String> {
+        let typ = self.get(db);
+        let mut names: Vec<_> = typ
+            .default_methods
+            .keys()
+            .chain(typ.required_methods.keys())
+            .collect();
+
+        for req in &typ.required_traits {
+            names.append(&mut req.instance_of.method_names(db));
+        }
+
+        names
+    }
+
     pub fn add_default_method(
         self,
         db: &mut Database,
@@ -1298,6 +1321,10 @@ impl ClassId {
         self.get(db).methods.get(name).is_some()
     }
 
+    fn method_names(self, db: &Database) -> Vec<&String> {
+        self.get(db).methods.keys().collect()
+    }
+
     pub fn add_method(self, db: &mut Database, name: String, method: MethodId) {
         self.get_mut(db).methods.insert(name, method);
     }
@@ -1859,6 +1886,59 @@ impl BuiltinFunction {
             BuiltinFunction::FloatPowi => TypeRef::float(),
         }
     }
+
+    /// Returns the number of arguments this function expects.
+    ///
+    /// A `None` is returned for functions that accept any number of
+    /// arguments.
+    pub fn arity(self) -> Option<usize> {
+        match self {
+            BuiltinFunction::State | BuiltinFunction::Process => Some(0),
+            BuiltinFunction::FloatCeil
+            | BuiltinFunction::FloatFloor
+            | BuiltinFunction::FloatFromBits
+            | BuiltinFunction::FloatIsInf
+            | BuiltinFunction::FloatIsNan
+            | BuiltinFunction::FloatRound
+            | BuiltinFunction::FloatToBits
+            | BuiltinFunction::IntBitNot
+            | BuiltinFunction::Moved
+            | BuiltinFunction::Panic => Some(1),
+            BuiltinFunction::FloatAdd
+            | BuiltinFunction::FloatDiv
+            | BuiltinFunction::FloatEq
+            | BuiltinFunction::FloatGe
+            | BuiltinFunction::FloatGt
+            | BuiltinFunction::FloatLe
+            | BuiltinFunction::FloatLt
+            | BuiltinFunction::FloatMod
+            | BuiltinFunction::FloatMul
+            | BuiltinFunction::FloatSub
+            | BuiltinFunction::FloatPowi
+            | BuiltinFunction::IntBitAnd
+            | BuiltinFunction::IntBitOr
+            | BuiltinFunction::IntBitXor
+            | BuiltinFunction::IntDiv
+            | BuiltinFunction::IntEq
+            | BuiltinFunction::IntGe
+            | BuiltinFunction::IntGt
+            | BuiltinFunction::IntLe
+            | BuiltinFunction::IntLt
+            | BuiltinFunction::IntRem
+            | BuiltinFunction::IntRotateLeft
+            | BuiltinFunction::IntRotateRight
+            | BuiltinFunction::IntShl
+            | BuiltinFunction::IntShr
+            | BuiltinFunction::IntUnsignedShr
+            | BuiltinFunction::IntWrappingAdd
+            | BuiltinFunction::IntWrappingMul
+            | BuiltinFunction::IntWrappingSub
+            | BuiltinFunction::IntCheckedAdd
+            | BuiltinFunction::IntCheckedMul
+            | BuiltinFunction::IntCheckedSub => Some(2),
+            BuiltinFunction::StringConcat => None,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -2640,6 +2720,13 @@ impl ModuleId {
         self.get(db).extern_methods.get(name).cloned()
     }
 
+    fn method_names(self, db: &Database) -> Vec<&String> {
+        let mut names = self.get(db).class.method_names(db);
+
+        names.extend(self.get(db).extern_methods.keys());
+        names
+    }
+
     pub fn extern_methods(self, db: &Database) -> &HashMap<String, MethodId> {
         &self.get(db).extern_methods
     }
@@ -4097,6 +4184,67 @@ impl TypeId {
         }
     }
 
+    /// Returns the name of a method that's similar to the given name, and
+    /// that can be called on `self`.
+    ///
+    /// This is used to suggest an alternative when calling an undefined
+    /// method, such as when the name contains a typo.
+    pub fn similar_method(
+        self,
+        db: &Database,
+        name: &str,
+        module: ModuleId,
+        allow_type_private: bool,
+    ) -> Option<String> {
+        let limit = (name.chars().count() / 3).max(1);
+        let mut found: Option<(usize, &String)> = None;
+
+        for candidate in self.method_names(db) {
+            // Methods generated by the compiler can't be called directly.
+            if candidate.starts_with('$') {
+                continue;
+            }
+
+            // A setter is never a useful suggestion for a regular method, and
+            // the other way around.
+            if candidate.ends_with('=') != name.ends_with('=') {
+                continue;
+            }
+
+            let distance = edit_distance(name, candidate);
+
+            if distance > limit
+                || found.map_or(false, |(dist, found)| {
+                    dist < distance || (dist == distance && found < candidate)
+                })
+            {
+                continue;
+            }
+
+            if let MethodLookup::Ok(_) =
+                self.lookup_method(db, candidate, module, allow_type_private)
+            {
+                found = Some((distance, candidate));
+            }
+        }
+
+        found.map(|(_, name)| name.clone())
+    }
+
+    fn method_names(self, db: &Database) -> Vec<&String> {
+        match self {
+            TypeId::Class(id) => id.method_names(db),
+            TypeId::Trait(id) => id.method_names(db),
+            TypeId::Module(id) => id.method_names(db),
+            TypeId::ClassInstance(id) => id.instance_of.method_names(db),
+            TypeId::TraitInstance(id) => id.instance_of.method_names(db),
+            TypeId::TypeParameter(id) | TypeId::RigidTypeParameter(id) => {
+                id.method_names(db)
+            }
+            _ => Vec::new(),
+        }
+    }
+
     pub fn use_dynamic_dispatch(self) -> bool {
         matches!(
             self,
@@ -4311,6 +4459,27 @@ impl Database {
     }
 }
 
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ac) in a.chars().enumerate() {
+        cur[0] = i + 1;
+
+        for (j, &bc) in b.iter().enumerate() {
+            let cost = usize::from(ac != bc);
+
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5206,4 +5375,93 @@ mod tests {
 
         assert_eq!(rec, mutable(instance(proc)));
     }
+
+    #[test]
+    fn test_builtin_function_arity() {
+        assert_eq!(BuiltinFunction::State.arity(), Some(0));
+        assert_eq!(BuiltinFunction::Process.arity(), Some(0));
+        assert_eq!(BuiltinFunction::Moved.arity(), Some(1));
+        assert_eq!(BuiltinFunction::FloatRound.arity(), Some(1));
+        assert_eq!(BuiltinFunction::Panic.arity(), Some(1));
+        assert_eq!(BuiltinFunction::IntBitNot.arity(), Some(1));
+        assert_eq!(BuiltinFunction::IntShl.arity(), Some(2));
+        assert_eq!(BuiltinFunction::FloatPowi.arity(), Some(2));
+        assert_eq!(BuiltinFunction::StringConcat.arity(), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("foo", "foo"), 0);
+        assert_eq!(edit_distance("foo", ""), 3);
+        assert_eq!(edit_distance("lenght", "length"), 2);
+        assert_eq!(edit_distance("size", "sizes"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_type_id_similar_method() {
+        let mut db = Database::new();
+        let module =
+            Module::alloc(&mut db, ModuleName::new("foo"), "foo.inko".into());
+        let class = new_class(&mut db, "A");
+        let length = Method::alloc(
+            &mut db,
+            module,
+            "length".to_string(),
+            Visibility::Public,
+            MethodKind::Instance,
+        );
+        let new = Method::alloc(
+            &mut db,
+            module,
+            "new".to_string(),
+            Visibility::Public,
+            MethodKind::Static,
+        );
+
+        let length_setter = Method::alloc(
+            &mut db,
+            module,
+            "length=".to_string(),
+            Visibility::Public,
+            MethodKind::Mutable,
+        );
+        let getter_only = new_class(&mut db, "B");
+
+        class.add_method(&mut db, "length".to_string(), length);
+        class.add_method(&mut db, "length=".to_string(), length_setter);
+        class.add_method(&mut db, "new".to_string(), new);
+        getter_only.add_method(&mut db, "length".to_string(), length);
+
+        let ins = instance(class);
+        let cls = TypeId::Class(class);
+
+        assert_eq!(
+            ins.similar_method(&db, "lenght", module, false),
+            Some("length".to_string())
+        );
+        assert_eq!(
+            ins.similar_method(&db, "lengt", module, false),
+            Some("length".to_string())
+        );
+        assert_eq!(
+            ins.similar_method(&db, "lenght=", module, false),
+            Some("length=".to_string())
+        );
+        assert_eq!(
+            ins.similar_method(&db, "lengt=", module, false),
+            Some("length=".to_string())
+        );
+        assert_eq!(
+            instance(getter_only).similar_method(&db, "lenght=", module, false),
+            None
+        );
+        assert_eq!(ins.similar_method(&db, "foo", module, false), None);
+        assert_eq!(ins.similar_method(&db, "nw", module, false), None);
+        assert_eq!(
+            cls.similar_method(&db, "nw", module, false),
+            Some("new".to_string())
+        );
+    }
 }
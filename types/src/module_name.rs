@@ -13,12 +13,18 @@ pub struct ModuleName {
 }
 
 impl ModuleName {
+    /// Returns a module name for a path relative to a source directory.
+    ///
+    /// Only the source file extension is removed, any other extension is kept
+    /// as part of the name.
     pub fn from_relative_path(path: &Path) -> Self {
-        Self::new(
+        let path = if path.extension().map_or(false, |e| e == SOURCE_EXT) {
             path.with_extension("")
-                .to_string_lossy()
-                .replace(MAIN_SEPARATOR, SEPARATOR),
-        )
+        } else {
+            path.to_path_buf()
+        };
+
+        Self::new(path.to_string_lossy().replace(MAIN_SEPARATOR, SEPARATOR))
     }
 
     pub fn main() -> Self {
@@ -98,6 +104,30 @@ mod tests {
         assert_eq!(name, ModuleName::new("foo.bar"));
     }
 
+    #[test]
+    fn test_from_relative_path_with_other_extension() {
+        let path = PathBuf::from("foo").join("bar.txt");
+        let name = ModuleName::from_relative_path(&path);
+
+        assert_eq!(name, ModuleName::new("foo.bar.txt"));
+    }
+
+    #[test]
+    fn test_from_relative_path_with_multiple_dots() {
+        let path = PathBuf::from("foo").join("bar.baz.inko");
+        let name = ModuleName::from_relative_path(&path);
+
+        assert_eq!(name, ModuleName::new("foo.bar.baz"));
+    }
+
+    #[test]
+    fn test_from_relative_path_without_extension() {
+        let path = PathBuf::from("foo").join("bar");
+        let name = ModuleName::from_relative_path(&path);
+
+        assert_eq!(name, ModuleName::new("foo.bar"));
+    }
+
     #[test]
     fn test_main() {
         assert_eq!(ModuleName::main(), ModuleName::new("main"));